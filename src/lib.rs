@@ -1,8 +1,11 @@
-use core::f64;
 use std::fmt::{Display, Formatter};
 
+use numeric::Numeric;
 use tableau::Tableau;
 
+pub mod model;
+pub mod numeric;
+pub mod sparse;
 pub mod tableau;
 
 #[derive(Debug)]
@@ -22,6 +25,7 @@ pub enum OptimizeResult {
     Optimal,
     MultipleOptimal,
     Unbounded,
+    Infeasible,
 }
 
 #[derive(Debug)]
@@ -31,13 +35,46 @@ pub enum FindPivotElementResult {
     Unbounded,
 }
 
+/// A read-only view over a tableau's cells, so the pivoting/optimize logic can be
+/// written once and shared between the dense `Tableau` and the `sparse` module's
+/// backend instead of being duplicated per representation.
+pub trait TableauView<T> {
+    fn row_count(&self) -> usize;
+    fn column_count(&self) -> usize;
+    fn get(&self, row: usize, column: usize) -> T;
+}
+
+impl<T: Clone> TableauView<T> for Vec<Vec<T>> {
+    fn row_count(&self) -> usize {
+        self.len()
+    }
+
+    fn column_count(&self) -> usize {
+        self[0].len()
+    }
+
+    fn get(&self, row: usize, column: usize) -> T {
+        self[row][column].clone()
+    }
+}
+
+/// How `find_pivot_column`/`find_pivot_row` choose the entering column and leaving row.
+/// `Dantzig` is fast but can cycle forever on degenerate problems; `Bland` always
+/// terminates at the cost of more pivots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PivotStrategy {
+    #[default]
+    Dantzig,
+    Bland,
+}
+
 #[derive(Debug)]
-pub enum TableauVectorVariable {
-    Basic(f64),
-    NonBasic(f64),
+pub enum TableauVectorVariable<T> {
+    Basic(T),
+    NonBasic(T),
 }
 
-impl Display for TableauVectorVariable {
+impl<T: Display> Display for TableauVectorVariable<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             TableauVectorVariable::Basic(value) => write!(f, "BV({})", value),
@@ -46,103 +83,105 @@ impl Display for TableauVectorVariable {
     }
 }
 
-pub fn find_pivot_column(row: &[f64]) -> Option<usize> {
-    let row_min_value = row.iter().fold(f64::INFINITY, |current_value, next_value| {
-        if *next_value < current_value {
-            *next_value
-        } else {
-            current_value
-        }
-    });
+pub fn find_pivot_column<T: Numeric>(row: &[T], strategy: PivotStrategy) -> Option<usize> {
+    match strategy {
+        PivotStrategy::Dantzig => {
+            let row_min_value = row.iter().cloned().reduce(
+                |current_value, next_value| {
+                    if next_value < current_value {
+                        next_value
+                    } else {
+                        current_value
+                    }
+                },
+            )?;
+
+            if row_min_value >= T::zero() {
+                return None;
+            }
 
-    if row_min_value >= 0.0 {
-        return None;
+            row.iter().position(|value| *value == row_min_value) // Value is guaranteed to be found
+        }
+        PivotStrategy::Bland => row.iter().position(|value| *value < T::zero()),
     }
-
-    let pivot_column = row
-        .iter()
-        .position(|&value| value == row_min_value)
-        .unwrap(); // Value is guaranteed to be found
-
-    Some(pivot_column)
 }
 
-pub fn find_pivot_row(pivot_column: &[f64], rhs_column: &[f64]) -> Option<usize> {
-    let pivot_column_max_value =
-        pivot_column
-            .iter()
-            .fold(f64::NEG_INFINITY, |current_value, next_value| {
-                if *next_value > current_value {
-                    *next_value
-                } else {
-                    current_value
-                }
-            });
-
-    if pivot_column_max_value <= 0.0 {
+pub fn find_pivot_row<T: Numeric>(
+    pivot_column: &[T],
+    rhs_column: &[T],
+    basic_columns: &[usize],
+    strategy: PivotStrategy,
+) -> Option<usize> {
+    let has_positive_entry = pivot_column.iter().any(|value| *value > T::zero());
+    if !has_positive_entry {
         return None;
     }
 
+    // A `None` quotient marks a row that cannot be the leaving row (non-positive
+    // pivot-column entry), standing in for the `f64::INFINITY` sentinel the
+    // non-generic ratio test used to rely on.
     let quotients = pivot_column
         .iter()
         .zip(rhs_column)
         .map(|(pivot_column_value, rhs_value)| {
-            if *pivot_column_value > 0.0 {
-                rhs_value / pivot_column_value
+            if *pivot_column_value > T::zero() {
+                Some(rhs_value.clone() / pivot_column_value.clone())
             } else {
-                f64::INFINITY
+                None
             }
         })
-        .collect::<Vec<f64>>();
+        .collect::<Vec<Option<T>>>();
 
-    let quotients_min_value = quotients
-        .iter()
-        .fold(f64::INFINITY, |current_value, next_value| {
-            if *next_value < current_value {
-                *next_value
+    let quotients_min_value = quotients.iter().flatten().cloned().reduce(
+        |current_value, next_value| {
+            if next_value < current_value {
+                next_value
             } else {
                 current_value
             }
-        });
+        },
+    )?;
 
-    let pivot_row = quotients
-        .iter()
-        .position(|&value| value == quotients_min_value)
-        .unwrap(); // Value is guaranteed to be found
+    let candidate_rows = quotients.iter().enumerate().filter_map(|(index, value)| {
+        match value {
+            Some(value) if *value == quotients_min_value => Some(index),
+            _ => None,
+        }
+    });
 
-    Some(pivot_row)
+    match strategy {
+        // First occurrence, matching the tie-break `find_pivot_column` used before
+        // `PivotStrategy` existed.
+        PivotStrategy::Dantzig => candidate_rows.min(),
+        // Bland's rule: break ties by the lowest column index of the leaving row's
+        // basic variable, which is what guarantees termination on degenerate problems.
+        PivotStrategy::Bland => candidate_rows.min_by_key(|&row| basic_columns[row]),
+    }
 }
 
-pub fn find_pivot_element(tableau: &Tableau) -> FindPivotElementResult {
-    let target_row_without_x0_rhs = &tableau.rows[0]
-        .iter()
-        .skip(1) // Skip x0 column
-        .take(tableau.rows[0].len() - 2) // Cut off RHS column
-        .copied()
-        .collect::<Vec<f64>>();
+pub fn find_pivot_element<T: Numeric>(view: &impl TableauView<T>, strategy: PivotStrategy) -> FindPivotElementResult {
+    let target_row_without_x0_rhs = (1..view.column_count() - 1) // Skip x0 column, cut off RHS column
+        .map(|column| view.get(0, column))
+        .collect::<Vec<T>>();
 
-    let pivot_column_index = match find_pivot_column(target_row_without_x0_rhs) {
+    let pivot_column_index = match find_pivot_column(&target_row_without_x0_rhs, strategy) {
         Some(pivot_column) => pivot_column,
         None => return FindPivotElementResult::Optimal,
     };
 
     let pivot_column_index = pivot_column_index + 1; // Make up for the x0 column skip
 
-    let pivot_column = tableau
-        .rows
-        .iter()
-        .skip(1) // Skip target row
-        .map(|row| row[pivot_column_index])
-        .collect::<Vec<f64>>();
+    let pivot_column = (1..view.row_count()) // Skip target row
+        .map(|row| view.get(row, pivot_column_index))
+        .collect::<Vec<T>>();
 
-    let rhs_column = tableau
-        .rows
-        .iter()
-        .skip(1) // Skip target row
-        .map(|row| *row.last().unwrap())
-        .collect::<Vec<f64>>();
+    let rhs_column = (1..view.row_count()) // Skip target row
+        .map(|row| view.get(row, view.column_count() - 1))
+        .collect::<Vec<T>>();
+
+    let basic_columns = basic_columns_by_row(view);
 
-    let pivot_row_index = match find_pivot_row(&pivot_column, &rhs_column) {
+    let pivot_row_index = match find_pivot_row(&pivot_column, &rhs_column, &basic_columns, strategy) {
         Some(pivot_row) => pivot_row,
         None => return FindPivotElementResult::Unbounded,
     };
@@ -153,36 +192,35 @@ pub fn find_pivot_element(tableau: &Tableau) -> FindPivotElementResult {
     FindPivotElementResult::Found(pivot_point)
 }
 
-pub fn get_vector(tableau: &Tableau) -> Vec<TableauVectorVariable> {
-    let mut vector = vec![];
-
-    for x in 0..tableau.rows[0].len() - 1 {
-        let column_values = tableau.rows.iter().map(|row| row[x]).collect::<Vec<f64>>();
-        let accumulated_value = column_values.iter().fold(0.0, |acc, &value| acc + value);
-        let is_basic = accumulated_value == 1.0;
+// A column is basic if it is the unit basis vector for some row, i.e. it is 1 in exactly
+// that row and 0 everywhere else. Checking whether the column merely sums to ~1 is not
+// enough: two non-basic fractional entries (e.g. 0.5 + 0.5) can also sum to 1 without
+// either one being the row's basic entry.
+fn is_unit_column<T: Numeric>(view: &impl TableauView<T>, row: usize, column: usize) -> bool {
+    view.get(row, column).approx_one()
+        && (0..view.row_count()).all(|other| other == row || view.get(other, column).approx_zero())
+}
 
-        if !is_basic {
-            vector.push(TableauVectorVariable::NonBasic(0.0));
-        } else {
-            let row_index = column_values
-                .iter()
-                .position(|&value| value == 1.0)
-                .unwrap(); // Value is guaranteed to be found
+pub fn get_vector<T: Numeric>(view: &impl TableauView<T>) -> Vec<TableauVectorVariable<T>> {
+    let mut vector = vec![];
 
-            let rhs_value = tableau.rows[row_index][tableau.rows[0].len() - 1];
+    for column in 0..view.column_count() - 1 {
+        let basic_row = (0..view.row_count()).find(|&row| is_unit_column(view, row, column));
 
-            vector.push(TableauVectorVariable::Basic(rhs_value));
+        match basic_row {
+            Some(row) => vector.push(TableauVectorVariable::Basic(view.get(row, view.column_count() - 1))),
+            None => vector.push(TableauVectorVariable::NonBasic(T::zero())),
         }
     }
 
     vector
 }
 
-pub fn pivot(tableau: &mut Tableau, pivot_element: &Point) {
-    let pivot_element_value = tableau.rows[pivot_element.y][pivot_element.x];
+pub fn pivot<T: Numeric>(tableau: &mut Tableau<T>, pivot_element: &Point) {
+    let pivot_element_value = tableau.rows[pivot_element.y][pivot_element.x].clone();
     tableau.rows[pivot_element.y] = tableau.rows[pivot_element.y]
         .iter()
-        .map(|value| *value / pivot_element_value)
+        .map(|value| value.clone() / pivot_element_value.clone())
         .collect();
 
     for y in 0..tableau.rows.len() {
@@ -190,80 +228,276 @@ pub fn pivot(tableau: &mut Tableau, pivot_element: &Point) {
             continue;
         }
 
-        let factor = tableau.rows[y][pivot_element.x];
+        let factor = tableau.rows[y][pivot_element.x].clone();
 
         for x in 0..tableau.rows[y].len() {
-            tableau.rows[y][x] -= factor * tableau.rows[pivot_element.y][x];
+            let pivot_row_value = tableau.rows[pivot_element.y][x].clone();
+            tableau.rows[y][x] = tableau.rows[y][x].clone() - factor.clone() * pivot_row_value;
         }
     }
 }
 
-pub fn optimize(tableau: Tableau) -> (OptimizeResult, Vec<Tableau>) {
+// When `find_pivot_element` reports optimal but some non-basic variable's reduced cost
+// is zero (per `Numeric::approx_zero`), the LP has multiple optima: pivoting on that column reaches an
+// equally-optimal adjacent vertex instead of the unique one `find_pivot_element` assumes.
+// A zero-reduced-cost column can still have no positive entry in any constraint row
+// (nothing to pivot into), so every such column is tried in turn rather than assuming
+// the first one works; if none do, there is no adjacent vertex to move to and the
+// current one really is optimal.
+fn find_degenerate_pivot<T: Numeric>(view: &impl TableauView<T>, strategy: PivotStrategy) -> Option<Point> {
+    let vector = get_vector(view);
+    let basic_columns = basic_columns_by_row(view);
+
+    let candidate_columns = vector.iter().enumerate().filter_map(|(index, variable)| match variable {
+        TableauVectorVariable::NonBasic(_) if view.get(0, index).approx_zero() => Some(index),
+        _ => None,
+    });
+
+    for pivot_column_index in candidate_columns {
+        let pivot_column = (1..view.row_count()) // Skip target row
+            .map(|row| view.get(row, pivot_column_index))
+            .collect::<Vec<T>>();
+
+        let rhs_column = (1..view.row_count()) // Skip target row
+            .map(|row| view.get(row, view.column_count() - 1))
+            .collect::<Vec<T>>();
+
+        if let Some(pivot_row) = find_pivot_row(&pivot_column, &rhs_column, &basic_columns, strategy) {
+            return Some(Point::new(pivot_column_index, pivot_row + 1)); // Make up for the target row skip
+        }
+    }
+
+    None
+}
+
+pub fn optimize<T: Numeric>(tableau: Tableau<T>, strategy: PivotStrategy) -> (OptimizeResult, Vec<Tableau<T>>) {
+    run_optimize_loop(tableau, strategy, pivot)
+}
+
+/// The pivoting loop shared by the dense `optimize` above and `sparse::optimize`: drive
+/// `find_pivot_element`/`find_degenerate_pivot` until one reports `Optimal`/`Unbounded`,
+/// applying `apply_pivot` to produce each next tableau. Generic over the tableau
+/// representation (`TableauView` for reading, `Clone` for keeping every intermediate
+/// tableau) so the two backends don't have to keep a second copy of this loop in sync.
+pub(crate) fn run_optimize_loop<T: Numeric, V: TableauView<T> + Clone>(
+    tableau: V,
+    strategy: PivotStrategy,
+    apply_pivot: impl Fn(&mut V, &Point),
+) -> (OptimizeResult, Vec<V>) {
     let mut tableaus = vec![tableau];
 
     loop {
         let last_tableau = tableaus.last().unwrap();
 
-        let pivot_element = match find_pivot_element(last_tableau) {
+        let pivot_element = match find_pivot_element(last_tableau, strategy) {
             FindPivotElementResult::Found(pivot_element) => pivot_element,
             FindPivotElementResult::Unbounded => return (OptimizeResult::Unbounded, tableaus),
-            FindPivotElementResult::Optimal => {
-                let target_row = &last_tableau.rows[0];
-                let vector = get_vector(last_tableau);
-
-                let nbv_indexes_with_target_row_values = vector
-                    .iter()
-                    .enumerate()
-                    .filter_map(|(index, variable)| match variable {
-                        TableauVectorVariable::NonBasic(_) => Some((index, target_row[index])),
-                        _ => None,
-                    })
-                    .collect::<Vec<(usize, f64)>>();
-
-                let is_degenerate = nbv_indexes_with_target_row_values
-                    .iter()
-                    .any(|(_, value)| *value == 0.0);
-
-                if !is_degenerate {
-                    return (OptimizeResult::Optimal, tableaus);
+            FindPivotElementResult::Optimal => match find_degenerate_pivot(last_tableau, strategy) {
+                None => return (OptimizeResult::Optimal, tableaus),
+                Some(pivot_element) => {
+                    let mut next_tableau = last_tableau.clone();
+                    apply_pivot(&mut next_tableau, &pivot_element);
+
+                    tableaus.push(next_tableau);
+                    return (OptimizeResult::MultipleOptimal, tableaus);
                 }
+            },
+        };
 
-                let pivot_column_index = nbv_indexes_with_target_row_values
-                    .iter()
-                    .find(|(_, value)| *value == 0.0)
-                    .unwrap() // Value is guaranteed to be found
-                    .0;
+        let mut next_tableau = last_tableau.clone();
+        apply_pivot(&mut next_tableau, &pivot_element);
 
-                let pivot_column = last_tableau
-                    .rows
-                    .iter()
-                    .skip(1) // Skip target row
-                    .map(|row| row[pivot_column_index])
-                    .collect::<Vec<f64>>();
+        tableaus.push(next_tableau);
+    }
+}
 
-                let rhs_column = last_tableau
-                    .rows
-                    .iter()
-                    .skip(1) // Skip target row
-                    .map(|row| *row.last().unwrap())
-                    .collect::<Vec<f64>>();
+/// Finds the column that holds the unit basis vector for `row_index`, if any,
+/// i.e. a column (other than x0 and RHS) that is 1 in this row and 0 in every other row.
+fn find_basic_column<T: Numeric>(view: &impl TableauView<T>, row_index: usize) -> Option<usize> {
+    (1..view.column_count() - 1).find(|&column| is_unit_column(view, row_index, column))
+}
 
-                let pivot_row = find_pivot_row(&pivot_column, &rhs_column).unwrap(); // Value is guaranteed to be found
-                let pivot_row = pivot_row + 1; // Make up for the target row skip
+/// The basic column for every constraint row (skipping the objective row), in the
+/// same row order `find_pivot_row` expects its `pivot_column`/`rhs_column` slices in.
+/// Rows without a clean unit basis (e.g. mid-construction) sort last under Bland's rule.
+fn basic_columns_by_row<T: Numeric>(view: &impl TableauView<T>) -> Vec<usize> {
+    (1..view.row_count())
+        .map(|row_index| find_basic_column(view, row_index).unwrap_or(usize::MAX))
+        .collect()
+}
 
-                let pivot_element = Point::new(pivot_column_index, pivot_row);
+/// Solves a `Tableau` that does not necessarily come with a ready-made feasible basis:
+/// rows without a unit basis column get an artificial variable, phase one maximizes the
+/// negated sum of those artificials, and phase two then optimizes the real objective.
+pub fn two_phase_optimize<T: Numeric>(
+    tableau: Tableau<T>,
+    strategy: PivotStrategy,
+) -> (OptimizeResult, Vec<Tableau<T>>) {
+    let columns = tableau.rows[0].len();
+    let rhs_index = columns - 1;
+
+    let infeasible_rows: Vec<usize> = (1..tableau.rows.len())
+        .filter(|&row_index| find_basic_column(&tableau.rows, row_index).is_none())
+        .collect();
 
-                let mut next_tableau = last_tableau.clone();
-                pivot(&mut next_tableau, &pivot_element);
+    if infeasible_rows.is_empty() {
+        return optimize(tableau, strategy);
+    }
 
-                tableaus.push(next_tableau);
-                return (OptimizeResult::MultipleOptimal, tableaus);
-            }
+    let artificial_count = infeasible_rows.len();
+    let new_columns = columns + artificial_count;
+    let new_rhs_index = new_columns - 1;
+
+    let mut phase_one_rows: Vec<Vec<T>> = tableau
+        .rows
+        .iter()
+        .map(|row| {
+            let mut new_row = row[..rhs_index].to_vec();
+            new_row.extend(std::iter::repeat_n(T::zero(), artificial_count));
+            new_row.push(row[rhs_index].clone());
+            new_row
+        })
+        .collect();
+
+    for (offset, &row_index) in infeasible_rows.iter().enumerate() {
+        phase_one_rows[row_index][rhs_index + offset] = T::one();
+    }
+
+    // Phase-one objective: maximize the negated sum of the artificial variables.
+    // Starting from "v + sum(artificial) = 0" and subtracting every artificial row
+    // cancels the artificial columns back to 0 and leaves the negated row sums
+    // everywhere else, exactly like eliminating any other basic variable.
+    let mut phase_one_objective = vec![T::zero(); new_columns];
+    phase_one_objective[0] = T::one();
+    for offset in 0..artificial_count {
+        phase_one_objective[rhs_index + offset] = T::one();
+    }
+    for &row_index in &infeasible_rows {
+        let row = phase_one_rows[row_index].clone();
+        for (objective_value, row_value) in phase_one_objective.iter_mut().zip(row) {
+            *objective_value = objective_value.clone() - row_value;
+        }
+    }
+    phase_one_rows[0] = phase_one_objective;
+
+    let phase_one_tableau = Tableau::new(phase_one_rows)
+        .expect("phase-one tableau has the same shape as the input tableau plus artificial columns");
+
+    let (_, phase_one_tableaus) = optimize(phase_one_tableau, strategy);
+    let phase_one_optimum = phase_one_tableaus.last().unwrap().rows[0][new_rhs_index].clone();
+
+    if phase_one_optimum < T::zero() {
+        return (OptimizeResult::Infeasible, phase_one_tableaus);
+    }
+
+    let feasible_tableau = phase_one_tableaus.last().unwrap();
+    let mut phase_two_rows: Vec<Vec<T>> = feasible_tableau
+        .rows
+        .iter()
+        .map(|row| {
+            let mut new_row = row[..rhs_index].to_vec();
+            new_row.push(row[new_rhs_index].clone());
+            new_row
+        })
+        .collect();
+
+    // Find each row's basic column while row 0 still holds the phase-one objective, which
+    // is 0 in every basic column; the original objective generally is not.
+    let basic_columns: Vec<Option<usize>> = (1..phase_two_rows.len())
+        .map(|row_index| find_basic_column(&phase_two_rows, row_index))
+        .collect();
+
+    phase_two_rows[0] = tableau.rows[0].clone();
+
+    for (row_index, basic_column) in (1..phase_two_rows.len()).zip(basic_columns) {
+        let basic_column = match basic_column {
+            Some(basic_column) => basic_column,
+            None => continue,
         };
 
-        let mut next_tableau = last_tableau.clone();
-        pivot(&mut next_tableau, &pivot_element);
+        let factor = phase_two_rows[0][basic_column].clone();
+        if factor != T::zero() {
+            let row = phase_two_rows[row_index].clone();
+            for (objective_value, row_value) in phase_two_rows[0].iter_mut().zip(row) {
+                *objective_value = objective_value.clone() - factor.clone() * row_value;
+            }
+        }
+    }
 
-        tableaus.push(next_tableau);
+    let phase_two_tableau =
+        Tableau::new(phase_two_rows).expect("phase-two tableau has the same shape as the input tableau");
+
+    let (result, mut phase_two_tableaus) = optimize(phase_two_tableau, strategy);
+
+    let mut tableaus = phase_one_tableaus;
+    tableaus.append(&mut phase_two_tableaus);
+
+    (result, tableaus)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_phase_optimize_handles_a_mixed_le_ge_problem() {
+        // max 2x1 + 3x2, s.t. x1 + x2 <= 4, x1 + 2x2 >= 2
+        let tableau = Tableau::new(vec![
+            vec![1.0, -2.0, -3.0, 0.0, 0.0, 0.0],
+            vec![0.0, 1.0, 1.0, 1.0, 0.0, 4.0],
+            vec![0.0, 1.0, 2.0, 0.0, -1.0, 2.0],
+        ])
+        .unwrap();
+
+        let (result, tableaus) = two_phase_optimize(tableau, PivotStrategy::default());
+
+        assert!(matches!(result, OptimizeResult::Optimal));
+        let final_tableau = tableaus.last().unwrap();
+        let objective_value = final_tableau.rows[0].last().unwrap();
+        assert!((objective_value - 12.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn optimize_reaches_the_same_optimum_under_blands_rule() {
+        // max 5x1 + 4x2, s.t. 6x1 + 4x2 <= 24, x1 + 2x2 <= 6
+        let tableau = Tableau::new(vec![
+            vec![1.0, -5.0, -4.0, 0.0, 0.0, 0.0],
+            vec![0.0, 6.0, 4.0, 1.0, 0.0, 24.0],
+            vec![0.0, 1.0, 2.0, 0.0, 1.0, 6.0],
+        ])
+        .unwrap();
+
+        let (result, tableaus) = optimize(tableau, PivotStrategy::Bland);
+
+        assert!(matches!(result, OptimizeResult::Optimal));
+        let objective_value = tableaus.last().unwrap().rows[0].last().unwrap();
+        assert!((objective_value - 21.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn two_phase_optimize_detects_infeasible_problems() {
+        // max x1, s.t. x1 <= 2, x1 >= 5
+        let tableau = Tableau::new(vec![
+            vec![1.0, -1.0, 0.0, 0.0, 0.0],
+            vec![0.0, 1.0, 1.0, 0.0, 2.0],
+            vec![0.0, 1.0, 0.0, -1.0, 5.0],
+        ])
+        .unwrap();
+
+        let (result, _) = two_phase_optimize(tableau, PivotStrategy::default());
+
+        assert!(matches!(result, OptimizeResult::Infeasible));
+    }
+
+    #[test]
+    fn two_phase_optimize_does_not_panic_on_a_ge_only_problem() {
+        // max -x1, s.t. x1 >= 5
+        let tableau = Tableau::new(vec![vec![1.0, 1.0, 0.0, 0.0], vec![0.0, 1.0, -1.0, 5.0]]).unwrap();
+
+        let (result, tableaus) = two_phase_optimize(tableau, PivotStrategy::default());
+
+        assert!(matches!(result, OptimizeResult::Optimal));
+        let objective_value = tableaus.last().unwrap().rows[0].last().unwrap();
+        assert!((objective_value - (-5.0)).abs() < 1e-6);
     }
 }
@@ -1,7 +1,8 @@
 use simplex::{
     optimize,
     tableau::Tableau,
-    OptimizeResult::{MultipleOptimal, Optimal, Unbounded},
+    OptimizeResult::{Infeasible, MultipleOptimal, Optimal, Unbounded},
+    PivotStrategy,
 };
 
 fn main() {
@@ -18,7 +19,7 @@ fn main() {
         }
     };
 
-    let (result, tableaus) = optimize(tableau);
+    let (result, tableaus) = optimize(tableau, PivotStrategy::default());
 
     for (index, tableau) in tableaus.iter().enumerate() {
         println!("Tableau {}:\n{}", (index + 1), tableau);
@@ -30,5 +31,6 @@ fn main() {
         Optimal => println!("Optimal"),
         MultipleOptimal => println!("Multiple optimal solutions. Check out both last tableaus."),
         Unbounded => println!("Unbounded"),
+        Infeasible => println!("Infeasible"),
     }
 }
@@ -0,0 +1,312 @@
+use thiserror::Error;
+
+use crate::tableau::{Tableau, TableauCreationError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    Maximize,
+    Minimize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    Le,
+    Ge,
+    Eq,
+}
+
+#[derive(Debug, Clone)]
+pub struct Constraint {
+    pub coefficients: Vec<f64>,
+    pub relation: Relation,
+    pub rhs: f64,
+}
+
+impl Constraint {
+    pub fn new(coefficients: Vec<f64>, relation: Relation, rhs: f64) -> Self {
+        Self {
+            coefficients,
+            relation,
+            rhs,
+        }
+    }
+}
+
+/// What a `Tableau` column built by `Model::to_tableau` stands for, so a solved
+/// vector can be mapped back onto the variables the user actually declared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnKind {
+    /// A variable the user declared, indexed into `Model::coefficients`.
+    Structural(usize),
+    /// Added to turn a `Relation::Le` constraint into an equality.
+    Slack(usize),
+    /// Added to turn a `Relation::Ge` constraint into an equality.
+    Surplus(usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct BuiltTableau {
+    pub tableau: Tableau<f64>,
+    pub columns: Vec<ColumnKind>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Model {
+    pub objective: Objective,
+    pub coefficients: Vec<f64>,
+    pub constraints: Vec<Constraint>,
+}
+
+impl Model {
+    pub fn new(objective: Objective, coefficients: Vec<f64>, constraints: Vec<Constraint>) -> Self {
+        Self {
+            objective,
+            coefficients,
+            constraints,
+        }
+    }
+
+    /// Builds the `Tableau` this model corresponds to: the objective row is negated
+    /// for `Maximize` (matching the x0 row convention `optimize` expects), and every
+    /// constraint gets the slack or surplus column its relation needs.
+    ///
+    /// A constraint with a negative RHS is flipped (coefficients, RHS and relation
+    /// negated/reversed) first, so every row starts with a non-negative RHS. `Ge` and
+    /// `Eq` rows are left without a ready-made unit basis on purpose: feed the result
+    /// through `two_phase_optimize` rather than `optimize` whenever the model has any,
+    /// so its own phase one builds and clears the artificial variables they need.
+    pub fn to_tableau(&self) -> Result<BuiltTableau, TableauCreationError> {
+        let variable_count = self.coefficients.len();
+
+        let constraints: Vec<Constraint> = self
+            .constraints
+            .iter()
+            .map(|constraint| {
+                if constraint.rhs >= 0.0 {
+                    return constraint.clone();
+                }
+
+                let coefficients = constraint.coefficients.iter().map(|coefficient| -coefficient).collect();
+                let relation = match constraint.relation {
+                    Relation::Le => Relation::Ge,
+                    Relation::Ge => Relation::Le,
+                    Relation::Eq => Relation::Eq,
+                };
+
+                Constraint::new(coefficients, relation, -constraint.rhs)
+            })
+            .collect();
+
+        let mut slack_count = 0;
+        let mut surplus_count = 0;
+        for constraint in &constraints {
+            match constraint.relation {
+                Relation::Le => slack_count += 1,
+                Relation::Ge => surplus_count += 1,
+                Relation::Eq => {}
+            }
+        }
+
+        let columns = 1 + variable_count + slack_count + surplus_count + 1;
+        let rhs_index = columns - 1;
+
+        let slack_start = 1 + variable_count;
+        let surplus_start = slack_start + slack_count;
+
+        let objective_sign = match self.objective {
+            Objective::Maximize => -1.0,
+            Objective::Minimize => 1.0,
+        };
+
+        let mut objective_row = vec![0.0; columns];
+        objective_row[0] = 1.0;
+        for (index, &coefficient) in self.coefficients.iter().enumerate() {
+            objective_row[1 + index] = objective_sign * coefficient;
+        }
+
+        let mut rows = vec![objective_row];
+
+        let mut next_slack = 0;
+        let mut next_surplus = 0;
+
+        for constraint in &constraints {
+            let mut row = vec![0.0; columns];
+            for (index, &coefficient) in constraint.coefficients.iter().enumerate() {
+                row[1 + index] = coefficient;
+            }
+
+            match constraint.relation {
+                Relation::Le => {
+                    row[slack_start + next_slack] = 1.0;
+                    next_slack += 1;
+                }
+                Relation::Ge => {
+                    row[surplus_start + next_surplus] = -1.0;
+                    next_surplus += 1;
+                }
+                Relation::Eq => {}
+            }
+
+            row[rhs_index] = constraint.rhs;
+            rows.push(row);
+        }
+
+        let mut columns = Vec::with_capacity(variable_count + slack_count + surplus_count);
+        columns.extend((0..variable_count).map(ColumnKind::Structural));
+        columns.extend((0..slack_count).map(ColumnKind::Slack));
+        columns.extend((0..surplus_count).map(ColumnKind::Surplus));
+
+        let tableau = Tableau::new(rows)?;
+        Ok(BuiltTableau { tableau, columns })
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ModelParseError {
+    #[error("expected a header line of \"num_constraints num_variables\"")]
+    MissingHeader,
+    #[error("header must contain exactly two numbers, got \"{0}\"")]
+    InvalidHeader(String),
+    #[error("expected an objective line after the header")]
+    MissingObjective,
+    #[error("objective line must start with \"max\" or \"min\", got \"{0}\"")]
+    InvalidObjectiveDirection(String),
+    #[error("objective line must declare {expected} coefficients, got {actual}")]
+    InvalidObjectiveArity { expected: usize, actual: usize },
+    #[error("expected {expected} constraint lines, got {actual}")]
+    MissingConstraints { expected: usize, actual: usize },
+    #[error("constraint line \"{0}\" must have {1} coefficients, a relation and a right-hand side")]
+    InvalidConstraint(String, usize),
+    #[error("unknown relation \"{0}\", expected one of \"<=\", \">=\", \"=\"")]
+    InvalidRelation(String),
+    #[error("could not parse number \"{0}\"")]
+    InvalidNumber(String),
+}
+
+/// Parses the compact LP format: a "num_constraints num_variables" header, an
+/// objective line ("max"/"min" followed by its coefficients), then one constraint
+/// line per row (coefficients, a relation of "<="/">="/"=", and a right-hand side).
+pub fn parse(input: &str) -> Result<Model, ModelParseError> {
+    let mut lines = input.lines().map(str::trim).filter(|line| !line.is_empty());
+
+    let header = lines.next().ok_or(ModelParseError::MissingHeader)?;
+    let mut header_parts = header.split_whitespace();
+    let num_constraints = header_parts
+        .next()
+        .and_then(|token| token.parse::<usize>().ok())
+        .ok_or_else(|| ModelParseError::InvalidHeader(header.to_string()))?;
+    let num_variables = header_parts
+        .next()
+        .and_then(|token| token.parse::<usize>().ok())
+        .ok_or_else(|| ModelParseError::InvalidHeader(header.to_string()))?;
+    if header_parts.next().is_some() {
+        return Err(ModelParseError::InvalidHeader(header.to_string()));
+    }
+
+    let objective_line = lines.next().ok_or(ModelParseError::MissingObjective)?;
+    let mut objective_parts = objective_line.split_whitespace();
+    let direction = objective_parts.next().ok_or(ModelParseError::MissingObjective)?;
+    let objective = match direction.to_ascii_lowercase().as_str() {
+        "max" => Objective::Maximize,
+        "min" => Objective::Minimize,
+        _ => return Err(ModelParseError::InvalidObjectiveDirection(direction.to_string())),
+    };
+
+    let coefficients = objective_parts
+        .map(|token| {
+            token
+                .parse::<f64>()
+                .map_err(|_| ModelParseError::InvalidNumber(token.to_string()))
+        })
+        .collect::<Result<Vec<f64>, _>>()?;
+    if coefficients.len() != num_variables {
+        return Err(ModelParseError::InvalidObjectiveArity {
+            expected: num_variables,
+            actual: coefficients.len(),
+        });
+    }
+
+    let mut constraints = Vec::with_capacity(num_constraints);
+    for _ in 0..num_constraints {
+        let line = lines.next().ok_or(ModelParseError::MissingConstraints {
+            expected: num_constraints,
+            actual: constraints.len(),
+        })?;
+
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        if tokens.len() != num_variables + 2 {
+            return Err(ModelParseError::InvalidConstraint(line.to_string(), num_variables));
+        }
+
+        let row_coefficients = tokens[..num_variables]
+            .iter()
+            .map(|token| {
+                token
+                    .parse::<f64>()
+                    .map_err(|_| ModelParseError::InvalidNumber(token.to_string()))
+            })
+            .collect::<Result<Vec<f64>, _>>()?;
+
+        let relation = match tokens[num_variables] {
+            "<=" => Relation::Le,
+            ">=" => Relation::Ge,
+            "=" => Relation::Eq,
+            other => return Err(ModelParseError::InvalidRelation(other.to_string())),
+        };
+
+        let rhs = tokens[num_variables + 1]
+            .parse::<f64>()
+            .map_err(|_| ModelParseError::InvalidNumber(tokens[num_variables + 1].to_string()))?;
+
+        constraints.push(Constraint::new(row_coefficients, relation, rhs));
+    }
+
+    Ok(Model::new(objective, coefficients, constraints))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{two_phase_optimize, OptimizeResult, PivotStrategy};
+
+    #[test]
+    fn parse_builds_the_expected_model() {
+        let model = parse("2 2\nmax 2 3\n1 1 <= 4\n1 2 >= 2\n").unwrap();
+
+        assert_eq!(model.objective, Objective::Maximize);
+        assert_eq!(model.coefficients, vec![2.0, 3.0]);
+        assert_eq!(model.constraints.len(), 2);
+        assert_eq!(model.constraints[0].relation, Relation::Le);
+        assert_eq!(model.constraints[1].relation, Relation::Ge);
+    }
+
+    #[test]
+    fn to_tableau_and_two_phase_optimize_agree_on_a_ge_constraint() {
+        // max -x1, s.t. x1 >= 5
+        let model = Model::new(Objective::Maximize, vec![-1.0], vec![Constraint::new(vec![1.0], Relation::Ge, 5.0)]);
+
+        let built = model.to_tableau().unwrap();
+        let (result, tableaus) = two_phase_optimize(built.tableau, PivotStrategy::default());
+
+        assert!(matches!(result, OptimizeResult::Optimal));
+        let objective_value = *tableaus.last().unwrap().rows[0].last().unwrap();
+        assert!((objective_value - (-5.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn to_tableau_normalizes_a_negative_rhs() {
+        // x1 >= 5 modeled as -x1 <= -5
+        let model = Model::new(
+            Objective::Maximize,
+            vec![-1.0],
+            vec![Constraint::new(vec![-1.0], Relation::Le, -5.0)],
+        );
+
+        let built = model.to_tableau().unwrap();
+        let (result, tableaus) = two_phase_optimize(built.tableau, PivotStrategy::default());
+
+        assert!(matches!(result, OptimizeResult::Optimal));
+        let objective_value = *tableaus.last().unwrap().rows[0].last().unwrap();
+        assert!((objective_value - (-5.0)).abs() < 1e-6);
+    }
+}
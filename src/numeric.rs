@@ -0,0 +1,68 @@
+use num_traits::Num;
+
+/// The numeric type bound shared by `Tableau` and the pivoting algorithms. `f64`
+/// allows a small tolerance since repeated `pivot` divisions can leave an exact 1 or
+/// 0 as e.g. `0.9999999998`; `BigRational` compares exactly.
+pub trait Numeric: Num + Clone + PartialOrd {
+    fn approx_one(&self) -> bool;
+    fn approx_zero(&self) -> bool;
+}
+
+const EPSILON: f64 = 1e-9;
+
+impl Numeric for f64 {
+    fn approx_one(&self) -> bool {
+        (self - 1.0).abs() < EPSILON
+    }
+
+    fn approx_zero(&self) -> bool {
+        self.abs() < EPSILON
+    }
+}
+
+impl Numeric for num_rational::BigRational {
+    fn approx_one(&self) -> bool {
+        num_traits::One::is_one(self)
+    }
+
+    fn approx_zero(&self) -> bool {
+        num_traits::Zero::is_zero(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+    use num_rational::BigRational;
+
+    use super::*;
+    use crate::{optimize, tableau::Tableau, OptimizeResult, PivotStrategy};
+
+    fn rational(numerator: i64, denominator: i64) -> BigRational {
+        BigRational::new(BigInt::from(numerator), BigInt::from(denominator))
+    }
+
+    #[test]
+    fn approx_one_and_approx_zero_are_exact_for_big_rational() {
+        assert!(rational(1, 1).approx_one());
+        assert!(!rational(1, 2).approx_one());
+        assert!(rational(0, 1).approx_zero());
+        assert!(!rational(1, 3).approx_zero());
+    }
+
+    #[test]
+    fn optimize_runs_exactly_over_big_rational() {
+        // max 2x1, s.t. x1 <= 3
+        let tableau = Tableau::new(vec![
+            vec![rational(1, 1), rational(-2, 1), rational(0, 1), rational(0, 1)],
+            vec![rational(0, 1), rational(1, 1), rational(1, 1), rational(3, 1)],
+        ])
+        .unwrap();
+
+        let (result, tableaus) = optimize(tableau, PivotStrategy::default());
+
+        assert!(matches!(result, OptimizeResult::Optimal));
+        let objective_value = tableaus.last().unwrap().rows[0].last().unwrap().clone();
+        assert_eq!(objective_value, rational(6, 1));
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    numeric::Numeric,
+    tableau::{Tableau, TableauCreationError},
+    OptimizeResult, PivotStrategy, Point, TableauView,
+};
+
+/// A `Tableau` backed by one ordered `(column_index, value)` list per row instead of a
+/// dense `Vec<Vec<T>>`, so `pivot` only has to touch rows with a nonzero pivot-column
+/// entry. Shares its pivoting/optimize logic with the dense `Tableau` through `TableauView`.
+#[derive(Debug, Clone)]
+pub struct SparseTableau<T> {
+    pub rows: Vec<Vec<(usize, T)>>,
+    pub columns: usize,
+}
+
+impl<T: Numeric> SparseTableau<T> {
+    pub fn new(rows: Vec<Vec<(usize, T)>>, columns: usize) -> Result<Self, TableauCreationError> {
+        if rows.len() < 2 {
+            return Err(TableauCreationError::NotEnoughRows(rows.len()));
+        }
+
+        if columns < 2 {
+            return Err(TableauCreationError::NotEnoughColumns);
+        }
+
+        Ok(Self { rows, columns })
+    }
+
+    pub fn from_dense(tableau: &Tableau<T>) -> Self {
+        let columns = tableau.rows[0].len();
+
+        let rows = tableau
+            .rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, value)| !value.approx_zero())
+                    .map(|(index, value)| (index, value.clone()))
+                    .collect()
+            })
+            .collect();
+
+        Self { rows, columns }
+    }
+
+    pub fn to_dense(&self) -> Tableau<T> {
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| {
+                let mut dense_row = vec![T::zero(); self.columns];
+                for (index, value) in row {
+                    dense_row[*index] = value.clone();
+                }
+                dense_row
+            })
+            .collect();
+
+        Tableau::new(rows).expect("sparse tableau rows all have the declared column count")
+    }
+
+    pub fn get(&self, row_index: usize, column_index: usize) -> T {
+        self.rows[row_index]
+            .iter()
+            .find(|(index, _)| *index == column_index)
+            .map(|(_, value)| value.clone())
+            .unwrap_or_else(T::zero)
+    }
+}
+
+/// Adds `-factor * pivot_row` onto `row`, touching only the columns either side is
+/// nonzero in, and dropping any entry that lands back on zero so sparsity survives
+/// repeated pivots instead of filling in with explicit zeros.
+fn subtract_scaled_row<T: Numeric>(row: &[(usize, T)], pivot_row: &[(usize, T)], factor: &T) -> Vec<(usize, T)> {
+    let mut merged: BTreeMap<usize, T> = row.iter().cloned().collect();
+
+    for (index, pivot_value) in pivot_row {
+        let updated = merged.remove(index).unwrap_or_else(T::zero) - factor.clone() * pivot_value.clone();
+        if !updated.approx_zero() {
+            merged.insert(*index, updated);
+        }
+    }
+
+    merged.into_iter().collect()
+}
+
+pub fn pivot<T: Numeric>(tableau: &mut SparseTableau<T>, pivot_element: &Point) {
+    let pivot_element_value = tableau.get(pivot_element.y, pivot_element.x);
+
+    tableau.rows[pivot_element.y] = tableau.rows[pivot_element.y]
+        .iter()
+        .map(|(index, value)| (*index, value.clone() / pivot_element_value.clone()))
+        .collect();
+
+    let pivot_row = tableau.rows[pivot_element.y].clone();
+
+    for y in 0..tableau.rows.len() {
+        if y == pivot_element.y {
+            continue;
+        }
+
+        let factor = tableau.get(y, pivot_element.x);
+        if factor.approx_zero() {
+            continue;
+        }
+
+        tableau.rows[y] = subtract_scaled_row(&tableau.rows[y], &pivot_row, &factor);
+    }
+}
+
+impl<T: Numeric> TableauView<T> for SparseTableau<T> {
+    fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn column_count(&self) -> usize {
+        self.columns
+    }
+
+    fn get(&self, row: usize, column: usize) -> T {
+        self.get(row, column)
+    }
+}
+
+pub fn optimize<T: Numeric>(tableau: SparseTableau<T>, strategy: PivotStrategy) -> (OptimizeResult, Vec<SparseTableau<T>>) {
+    crate::run_optimize_loop(tableau, strategy, pivot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_vector;
+
+    #[test]
+    fn optimize_agrees_with_the_dense_backend() {
+        // max 5x1 + 4x2, s.t. 6x1 + 4x2 <= 24, x1 + 2x2 <= 6
+        let dense_tableau = Tableau::new(vec![
+            vec![1.0, -5.0, -4.0, 0.0, 0.0, 0.0],
+            vec![0.0, 6.0, 4.0, 1.0, 0.0, 24.0],
+            vec![0.0, 1.0, 2.0, 0.0, 1.0, 6.0],
+        ])
+        .unwrap();
+        let sparse_tableau = SparseTableau::from_dense(&dense_tableau);
+
+        let (result, tableaus) = optimize(sparse_tableau, PivotStrategy::default());
+
+        assert!(matches!(result, OptimizeResult::Optimal));
+        let final_tableau = tableaus.last().unwrap();
+        assert_eq!(final_tableau.get(0, final_tableau.columns - 1), 21.0);
+        assert_eq!(get_vector(final_tableau).len(), final_tableau.columns - 1);
+    }
+}
@@ -2,7 +2,9 @@ use std::fmt::{Display, Formatter};
 
 use thiserror::Error;
 
-use crate::{find_pivot_element, get_vector, FindPivotElementResult::Found};
+use crate::{
+    find_pivot_element, get_vector, numeric::Numeric, FindPivotElementResult::Found, PivotStrategy, TableauView,
+};
 
 #[derive(Debug, Error)]
 pub enum TableauCreationError {
@@ -15,12 +17,12 @@ pub enum TableauCreationError {
 }
 
 #[derive(Debug, Clone)]
-pub struct Tableau {
-    pub rows: Vec<Vec<f64>>,
+pub struct Tableau<T> {
+    pub rows: Vec<Vec<T>>,
 }
 
-impl Tableau {
-    pub fn new(rows: Vec<Vec<f64>>) -> Result<Tableau, TableauCreationError> {
+impl<T> Tableau<T> {
+    pub fn new(rows: Vec<Vec<T>>) -> Result<Tableau<T>, TableauCreationError> {
         if rows.len() < 2 {
             return Err(TableauCreationError::NotEnoughRows(rows.len()));
         }
@@ -42,31 +44,49 @@ impl Tableau {
 
         Ok(Self { rows })
     }
+}
 
-    pub fn apply_all(&mut self, function: impl Fn(f64) -> f64) {
+impl<T: Clone> Tableau<T> {
+    pub fn apply_all(&mut self, function: impl Fn(T) -> T) {
         for row in &mut self.rows {
             for cell in row {
-                let cell_value = *cell;
+                let cell_value = cell.clone();
                 *cell = function(cell_value);
             }
         }
     }
 
-    pub fn apply_row(&mut self, row_index: usize, function: impl Fn(f64) -> f64) {
+    pub fn apply_row(&mut self, row_index: usize, function: impl Fn(T) -> T) {
         for cell in &mut self.rows[row_index] {
-            let cell_value = *cell;
+            let cell_value = cell.clone();
             *cell = function(cell_value);
         }
     }
 
-    pub fn apply_column(&mut self, column_index: usize, function: impl Fn(f64) -> f64) {
+    pub fn apply_column(&mut self, column_index: usize, function: impl Fn(T) -> T) {
         for row in &mut self.rows {
             let cell = &mut row[column_index];
-            let cell_value = *cell;
+            let cell_value = cell.clone();
             *cell = function(cell_value);
         }
     }
+}
+
+impl<T: Clone> TableauView<T> for Tableau<T> {
+    fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn column_count(&self) -> usize {
+        self.rows[0].len()
+    }
+
+    fn get(&self, row: usize, column: usize) -> T {
+        self.rows[row][column].clone()
+    }
+}
 
+impl<T: Display> Tableau<T> {
     fn get_column_width(&self, column_index: usize) -> usize {
         self.rows
             .iter()
@@ -76,7 +96,7 @@ impl Tableau {
     }
 }
 
-impl Display for Tableau {
+impl<T: Numeric + Display> Display for Tableau<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let column_widths: Vec<usize> = (0..self.rows[0].len())
             .map(|column_index| self.get_column_width(column_index))
@@ -141,7 +161,7 @@ impl Display for Tableau {
         }
         write!(f, ")")?;
 
-        let pivot_element = match find_pivot_element(self) {
+        let pivot_element = match find_pivot_element(self, PivotStrategy::default()) {
             Found(point) => point,
             _ => return Ok(()),
         };